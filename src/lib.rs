@@ -1,8 +1,44 @@
 ///! A crate providing a simple data structure for caching id values.
 ///!
 ///! See the documentation for the [`IdCache<I, T>`] type for more information.
+use hashbrown::hash_map::{RawEntryMut, RawVacantEntryMut};
 use id_collections::{Count, Id, IdVec};
-use std::{borrow::Borrow, collections::HashMap, fmt::Debug, hash::Hash, ops::Index};
+use std::{
+    borrow::Borrow,
+    collections::hash_map::RandomState,
+    fmt::Debug,
+    hash::{BuildHasher, Hash},
+    ops::{Deref, Index},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// Hashes `value` using the given hash builder, the same way a [`std::collections::HashMap`]
+/// would hash one of its keys.
+fn hash_value<X: Hash + ?Sized, S: BuildHasher>(hash_builder: &S, value: &X) -> u64 {
+    hash_builder.hash_one(value)
+}
+
+/// A sentinel `cache_id` meaning "unchecked": used for [`Interned`] handles deserialized without
+/// any particular [`IdCache`] in hand, so [`IdCache::resolve`] cannot meaningfully validate them.
+const UNCHECKED_CACHE_ID: u64 = 0;
+
+/// Returns a fresh id identifying one `IdCache<I, T, S>` instance, used to check that an
+/// [`Interned`] handle is resolved against the cache that produced it.
+fn next_cache_id() -> u64 {
+    static NEXT_CACHE_ID: AtomicU64 = AtomicU64::new(UNCHECKED_CACHE_ID + 1);
+    NEXT_CACHE_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Inserts `value` into a slot of `id_to_value`, reusing a slot from `free_ids` if one is
+/// available, and returns the id of the slot used.
+fn insert_live<I: Id, T>(id_to_value: &mut IdVec<I, Option<T>>, free_ids: &mut Vec<I>, value: T) -> I {
+    if let Some(id) = free_ids.pop() {
+        id_to_value[id] = Some(value);
+        id
+    } else {
+        id_to_value.push(Some(value))
+    }
+}
 
 /// A cache which generates sequentially-assigned ids for unique values.
 ///
@@ -27,11 +63,39 @@ use std::{borrow::Borrow, collections::HashMap, fmt::Debug, hash::Hash, ops::Ind
 /// assert_eq!(word_cache.make_id("foo"), foo_id);
 /// ```
 ///
+/// # Custom Hashers
+///
+/// `IdCache<I, T, S>` takes a third, optional type parameter `S` for the `BuildHasher` used to
+/// hash values, mirroring [`std::collections::HashMap`]'s own hasher parameter. This defaults to
+/// [`RandomState`], but a faster non-cryptographic hasher can be plugged in with
+/// [`IdCache::with_hasher`] or [`IdCache::with_capacity_and_hasher`] when `make_id` is called
+/// often enough for hashing to matter, such as in a compiler's interning tables.
+///
+/// # Internal Representation
+///
+/// Each unique value is stored exactly once, in `id_to_value`. The reverse lookup from value to
+/// id does not store a second copy of the value; instead it is a bare table of ids, probed with
+/// [`hashbrown`]'s `raw_entry` API by hashing the candidate value and comparing it against
+/// `id_to_value[id]` for each id with a matching hash. This is why `make_id` only requires
+/// `T: Eq + Hash`, and not `T: Clone`.
+///
+/// # Removal and Id Recycling
+///
+/// [`remove_id`](Self::remove_id) and [`remove_value`](Self::remove_value) retire a value and
+/// place its id on an internal free list, which later calls to `make_id`, `make_id_with`, or
+/// `entry` draw from before allocating a fresh id. This keeps ids compact under a
+/// remove-and-reinsert workload, but it also means a removed id must not be treated as if it
+/// still identifies its former value: once recycled, the same id will resolve to whatever new
+/// value claimed it. This also undermines [`Interned`]'s own validity check once a handle has
+/// been serialized and deserialized; see [`Interned`]'s documentation for why.
+///
 /// # Serde Support
 ///
 /// When the `serde` Cargo feature is enabled, the `IdCache<I, T>` type can be serialized and
 /// deserialized using [Serde](https://serde.rs). An `IdCache<I, T>` is serialized as a sequence
-/// consisting of the unique values in the cache, ordered by id:
+/// consisting of the unique, live values in the cache, ordered by id; any ids freed by removal
+/// are not preserved across a round trip, and the deserialized cache renumbers the remaining
+/// values compactly from `0`:
 ///
 /// ```
 /// # #[cfg(feature = "serde")]
@@ -51,37 +115,94 @@ use std::{borrow::Borrow, collections::HashMap, fmt::Debug, hash::Hash, ops::Ind
 /// # }
 /// ```
 #[derive(Clone)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
-#[cfg_attr(feature = "serde", serde(transparent))]
-pub struct IdCache<I: Id, T> {
-    #[cfg_attr(feature = "serde", serde(bound(serialize = "T: serde::Serialize")))]
-    id_to_value: IdVec<I, T>,
-    #[cfg_attr(feature = "serde", serde(skip))]
-    value_to_id: HashMap<T, I>,
+pub struct IdCache<I: Id, T, S = RandomState> {
+    // Sparse: a `None` slot is a removed value whose id is also on `free_ids`.
+    id_to_value: IdVec<I, Option<T>>,
+    // Stores only ids, keyed by the hash of their associated value in `id_to_value`. Looked up
+    // and inserted into via `hashbrown`'s `raw_entry` API so that `T` is never duplicated into
+    // this table.
+    value_to_id: hashbrown::HashMap<I, (), ()>,
+    hash_builder: S,
+    free_ids: Vec<I>,
+    len: usize,
+    cache_id: u64,
 }
 
 #[cfg(feature = "serde")]
-impl<'de, I: Id, T: Eq + Hash + Clone + serde::Deserialize<'de>> serde::Deserialize<'de>
-    for IdCache<I, T>
+impl<I: Id, T: serde::Serialize, S> serde::Serialize for IdCache<I, T, S> {
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.len))?;
+        for (_, value) in &self.id_to_value {
+            if let Some(value) = value {
+                seq.serialize_element(value)?;
+            }
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, I: Id, T: Eq + Hash + serde::Deserialize<'de>, S: BuildHasher + Default>
+    serde::Deserialize<'de> for IdCache<I, T, S>
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        let id_to_value = IdVec::<I, T>::deserialize(deserializer)?;
+        let values = Vec::<T>::deserialize(deserializer)?;
+        let len = values.len();
+        let hash_builder = S::default();
+
+        let mut id_to_value = IdVec::<I, Option<T>>::with_capacity(len);
+        let mut value_to_id = hashbrown::HashMap::with_capacity_and_hasher(len, ());
 
-        let mut value_to_id = HashMap::new();
-        for (id, value) in &id_to_value {
-            let existing = value_to_id.insert(value.clone(), id);
-            if existing.is_some() {
+        for value in values {
+            let hash = hash_value(&hash_builder, &value);
+
+            let duplicate = {
+                let id_to_value = &id_to_value;
+                value_to_id
+                    .raw_entry()
+                    .from_hash(hash, |&existing_id: &I| {
+                        id_to_value[existing_id].as_ref() == Some(&value)
+                    })
+                    .is_some()
+            };
+            if duplicate {
                 use serde::de::Error;
                 return Err(D::Error::custom("duplicate value in IdCache"));
             }
+
+            let id = id_to_value.push(Some(value));
+            let id_to_value_ref = &id_to_value;
+            let hash_builder_ref = &hash_builder;
+            if let RawEntryMut::Vacant(entry) = value_to_id
+                .raw_entry_mut()
+                .from_hash(hash, |&existing_id| existing_id == id)
+            {
+                entry.insert_with_hasher(hash, id, (), |&existing_id| {
+                    hash_value(
+                        hash_builder_ref,
+                        id_to_value_ref[existing_id]
+                            .as_ref()
+                            .expect("id_cache: live id missing its value"),
+                    )
+                });
+            }
         }
 
         Ok(IdCache {
             id_to_value,
             value_to_id,
+            hash_builder,
+            free_ids: Vec::new(),
+            len,
+            cache_id: next_cache_id(),
         })
     }
 }
@@ -122,19 +243,25 @@ impl<I: Id, T> Default for IdCache<I, T> {
     }
 }
 
-impl<I: Id + Debug, T: Debug> Debug for IdCache<I, T> {
+impl<I: Id + Debug, T: Debug, S> Debug for IdCache<I, T, S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.id_to_value.fmt(f)
+        f.debug_map()
+            .entries(
+                (&self.id_to_value)
+                    .into_iter()
+                    .filter_map(|(id, value)| value.as_ref().map(|value| (id, value))),
+            )
+            .finish()
     }
 }
 
-impl<I: Id, T: PartialEq> PartialEq for IdCache<I, T> {
+impl<I: Id, T: PartialEq, S> PartialEq for IdCache<I, T, S> {
     fn eq(&self, other: &Self) -> bool {
         self.id_to_value == other.id_to_value
     }
 }
 
-impl<I: Id, T: Eq> Eq for IdCache<I, T> {}
+impl<I: Id, T: Eq, S> Eq for IdCache<I, T, S> {}
 
 impl<I: Id, T> IdCache<I, T> {
     /// Constructs a new, empty `IdCache<I, T>`.
@@ -149,7 +276,11 @@ impl<I: Id, T> IdCache<I, T> {
     pub fn new() -> Self {
         IdCache {
             id_to_value: IdVec::new(),
-            value_to_id: HashMap::new(),
+            value_to_id: hashbrown::HashMap::with_hasher(()),
+            hash_builder: RandomState::new(),
+            free_ids: Vec::new(),
+            len: 0,
+            cache_id: next_cache_id(),
         }
     }
 
@@ -166,12 +297,63 @@ impl<I: Id, T> IdCache<I, T> {
     pub fn with_capacity(capacity: usize) -> Self {
         IdCache {
             id_to_value: IdVec::with_capacity(capacity),
-            value_to_id: HashMap::with_capacity(capacity),
+            value_to_id: hashbrown::HashMap::with_capacity_and_hasher(capacity, ()),
+            hash_builder: RandomState::new(),
+            free_ids: Vec::new(),
+            len: 0,
+            cache_id: next_cache_id(),
+        }
+    }
+}
+
+impl<I: Id, T, S> IdCache<I, T, S> {
+    /// Constructs a new, empty `IdCache<I, T, S>` which will use the given hash builder to hash
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::hash_map::RandomState;
+    /// # use id_cache::IdCache;
+    /// let mut cache: IdCache<u32, &str, RandomState> = IdCache::with_hasher(RandomState::new());
+    /// assert!(cache.is_empty());
+    /// ```
+    pub fn with_hasher(hasher: S) -> Self {
+        IdCache {
+            id_to_value: IdVec::new(),
+            value_to_id: hashbrown::HashMap::with_hasher(()),
+            hash_builder: hasher,
+            free_ids: Vec::new(),
+            len: 0,
+            cache_id: next_cache_id(),
+        }
+    }
+
+    /// Constructs a new, empty `IdCache<I, T, S>` with space to hold at least `capacity` unique
+    /// values, which will use the given hash builder to hash values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::hash_map::RandomState;
+    /// # use id_cache::IdCache;
+    /// let mut cache: IdCache<u32, &str, RandomState> =
+    ///     IdCache::with_capacity_and_hasher(100, RandomState::new());
+    /// assert!(cache.is_empty());
+    /// ```
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Self {
+        IdCache {
+            id_to_value: IdVec::with_capacity(capacity),
+            value_to_id: hashbrown::HashMap::with_capacity_and_hasher(capacity, ()),
+            hash_builder: hasher,
+            free_ids: Vec::new(),
+            len: 0,
+            cache_id: next_cache_id(),
         }
     }
 
-    /// Returns the total number of ids that have been assigned to unique values in the
-    /// `IdCache<I, T>`.
+    /// Returns the total number of ids that have ever been assigned to unique values in the
+    /// `IdCache<I, T, S>`, including ids which have since been freed by removal.
     ///
     /// # Examples
     ///
@@ -189,7 +371,7 @@ impl<I: Id, T> IdCache<I, T> {
         self.id_to_value.count()
     }
 
-    /// Returns the total number of unique values in the `IdCache<I, T>`.
+    /// Returns the total number of unique values currently in the `IdCache<I, T, S>`.
     ///
     /// # Examples
     ///
@@ -204,10 +386,10 @@ impl<I: Id, T> IdCache<I, T> {
     /// assert_eq!(cache.len(), 2);
     /// ```
     pub fn len(&self) -> usize {
-        self.id_to_value.len()
+        self.len
     }
 
-    /// Returns `true` if the `IdCache<I, T>` contains no values.
+    /// Returns `true` if the `IdCache<I, T, S>` contains no values.
     ///
     /// # Examples
     ///
@@ -219,17 +401,86 @@ impl<I: Id, T> IdCache<I, T> {
     /// assert!(!cache.is_empty());
     /// ```
     pub fn is_empty(&self) -> bool {
-        self.id_to_value.is_empty()
+        self.len == 0
     }
 
-    /// Ensures `value` has an id in the `IdCache<I, T>`, and returns that id.
+    /// Returns a reference to the value in the `IdCache<I, T, S>` associated with a given `id`,
+    /// or `None` if the id has not been assigned, or has since been removed.
     ///
-    /// If `value` is already present in the `IdCache<I, T>`, then `make_id` returns its existing
-    /// id. Otherwise, `make_id` returns a new sequentally-assigned id.
+    /// # Examples
+    ///
+    /// ```
+    /// # use id_cache::IdCache;
+    /// let mut cache: IdCache<u32, &str> = IdCache::new();
+    /// let foo_id = cache.make_id("foo");
+    /// assert_eq!(foo_id, 0);
+    /// assert_eq!(cache.get_value(foo_id), Some(&"foo"));
+    /// assert_eq!(cache.get_value(1), None);
+    /// ```
+    pub fn get_value(&self, id: I) -> Option<&T> {
+        self.id_to_value.get(id)?.as_ref()
+    }
+
+    /// Returns an iterator over the `(id, value)` pairs of the unique values in the
+    /// `IdCache<I, T, S>`, ordered by id.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use id_cache::IdCache;
+    /// let mut cache: IdCache<u32, &str> = IdCache::new();
+    /// cache.make_id("foo");
+    /// cache.make_id("bar");
+    /// assert_eq!(cache.iter().collect::<Vec<_>>(), vec![(0, &"foo"), (1, &"bar")]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (I, &T)> {
+        (&self.id_to_value)
+            .into_iter()
+            .filter_map(|(id, value)| value.as_ref().map(|value| (id, value)))
+    }
+
+    /// Returns an iterator over the unique values in the `IdCache<I, T, S>`, ordered by id.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use id_cache::IdCache;
+    /// let mut cache: IdCache<u32, &str> = IdCache::new();
+    /// cache.make_id("foo");
+    /// cache.make_id("bar");
+    /// assert_eq!(cache.values().collect::<Vec<_>>(), vec![&"foo", &"bar"]);
+    /// ```
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over the ids of the values currently in the `IdCache<I, T, S>`, in
+    /// order. Ids freed by removal and not yet reused are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use id_cache::IdCache;
+    /// let mut cache: IdCache<u32, &str> = IdCache::new();
+    /// cache.make_id("foo");
+    /// cache.make_id("bar");
+    /// assert_eq!(cache.ids().collect::<Vec<_>>(), vec![0, 1]);
+    /// ```
+    pub fn ids(&self) -> impl Iterator<Item = I> + '_ {
+        self.iter().map(|(id, _)| id)
+    }
+}
+
+impl<I: Id, T, S: BuildHasher> IdCache<I, T, S> {
+    /// Ensures `value` has an id in the `IdCache<I, T, S>`, and returns that id.
+    ///
+    /// If `value` is already present in the `IdCache<I, T, S>`, then `make_id` returns its
+    /// existing id. Otherwise, `make_id` returns a new id, which may be recycled from a
+    /// previously [`remove`d](Self::remove_id) value if one is available.
     ///
     /// # Panics
     ///
-    /// Panics if the number of ids in the `IdCache<I, T>` overflows `I`.
+    /// Panics if the number of ids in the `IdCache<I, T, S>` overflows `I`.
     ///
     /// # Examples
     ///
@@ -242,15 +493,38 @@ impl<I: Id, T> IdCache<I, T> {
     /// ```
     pub fn make_id(&mut self, value: T) -> I
     where
-        T: Eq + Hash + Clone,
+        T: Eq + Hash,
     {
-        *self
+        let hash = hash_value(&self.hash_builder, &value);
+
+        let id_to_value = &self.id_to_value;
+        let entry = self
             .value_to_id
-            .entry(value)
-            .or_insert_with_key(|value| self.id_to_value.push(value.clone()))
+            .raw_entry_mut()
+            .from_hash(hash, |&id| id_to_value[id].as_ref() == Some(&value));
+
+        match entry {
+            RawEntryMut::Occupied(entry) => *entry.key(),
+            RawEntryMut::Vacant(entry) => {
+                let id = insert_live(&mut self.id_to_value, &mut self.free_ids, value);
+                self.len += 1;
+                let hash_builder = &self.hash_builder;
+                let id_to_value = &self.id_to_value;
+                let (&mut id, _) = entry.insert_with_hasher(hash, id, (), |&id| {
+                    hash_value(
+                        hash_builder,
+                        id_to_value[id]
+                            .as_ref()
+                            .expect("id_cache: live id missing its value"),
+                    )
+                });
+                id
+            }
+        }
     }
 
-    /// Returns the id of a value in the `IdCache<I, T>`, or `None` if the value is not present.
+    /// Returns the id of a value in the `IdCache<I, T, S>`, or `None` if the value is not
+    /// present.
     ///
     /// # Examples
     ///
@@ -266,11 +540,120 @@ impl<I: Id, T> IdCache<I, T> {
         T: Borrow<U> + Eq + Hash,
         U: Eq + Hash,
     {
-        self.value_to_id.get(value).cloned()
+        let hash = hash_value(&self.hash_builder, value);
+        let id_to_value = &self.id_to_value;
+        self.value_to_id
+            .raw_entry()
+            .from_hash(hash, |&id| {
+                id_to_value[id].as_ref().map(Borrow::borrow) == Some(value)
+            })
+            .map(|(&id, &())| id)
     }
 
-    /// Returns a reference to the value in the `IdCache<I, T>` associated with a given `id`, or
-    /// `None` if the id has not been assigned.
+    /// Ensures a value borrowed as `key` has an id in the `IdCache<I, T, S>`, and returns that
+    /// id.
+    ///
+    /// If `key` is already present, `make_id_with` returns its existing id without calling
+    /// `make`. Otherwise, `make` is called to construct the owned value to insert, and
+    /// `make_id_with` returns its new id. This is useful when `T` is expensive to construct (for
+    /// example, an owned `String` interned from a borrowed `&str`) and the common case is a
+    /// cache hit, since `make` is only called on a miss.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of ids in the `IdCache<I, T, S>` overflows `I`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use id_cache::IdCache;
+    /// let mut cache: IdCache<u32, String> = IdCache::new();
+    /// let foo_id = cache.make_id_with("foo", || "foo".to_owned());
+    /// assert_eq!(cache.make_id_with("foo", || panic!("should not be called")), foo_id);
+    /// ```
+    pub fn make_id_with<U, F>(&mut self, key: &U, make: F) -> I
+    where
+        T: Borrow<U> + Eq + Hash,
+        U: ?Sized + Eq + Hash,
+        F: FnOnce() -> T,
+    {
+        let hash = hash_value(&self.hash_builder, key);
+
+        let id_to_value = &self.id_to_value;
+        let entry = self.value_to_id.raw_entry_mut().from_hash(hash, |&id| {
+            id_to_value[id].as_ref().map(Borrow::borrow) == Some(key)
+        });
+
+        match entry {
+            RawEntryMut::Occupied(entry) => *entry.key(),
+            RawEntryMut::Vacant(entry) => {
+                let id = insert_live(&mut self.id_to_value, &mut self.free_ids, make());
+                self.len += 1;
+                let hash_builder = &self.hash_builder;
+                let id_to_value = &self.id_to_value;
+                let (&mut id, _) = entry.insert_with_hasher(hash, id, (), |&id| {
+                    hash_value(
+                        hash_builder,
+                        id_to_value[id]
+                            .as_ref()
+                            .expect("id_cache: live id missing its value"),
+                    )
+                });
+                id
+            }
+        }
+    }
+
+    /// Returns the given value's [`Entry`] in the `IdCache<I, T, S>` for in-place lookup,
+    /// insertion, or modification.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use id_cache::IdCache;
+    /// let mut cache: IdCache<u32, &str> = IdCache::new();
+    /// let foo_id = cache.entry("foo").or_id();
+    /// assert_eq!(cache.entry("foo").or_id(), foo_id);
+    /// ```
+    pub fn entry(&mut self, value: T) -> Entry<'_, I, T, S>
+    where
+        T: Eq + Hash,
+    {
+        let hash = hash_value(&self.hash_builder, &value);
+
+        let id_to_value = &self.id_to_value;
+        let raw_entry = self
+            .value_to_id
+            .raw_entry_mut()
+            .from_hash(hash, |&id| id_to_value[id].as_ref() == Some(&value));
+
+        match raw_entry {
+            RawEntryMut::Occupied(entry) => {
+                let id = *entry.key();
+                Entry::Occupied(OccupiedEntry {
+                    id_to_value: &mut self.id_to_value,
+                    id,
+                })
+            }
+            RawEntryMut::Vacant(entry) => Entry::Vacant(VacantEntry {
+                id_to_value: &mut self.id_to_value,
+                free_ids: &mut self.free_ids,
+                len: &mut self.len,
+                raw_entry: entry,
+                hash,
+                hash_builder: &self.hash_builder,
+                value,
+            }),
+        }
+    }
+
+    /// Removes the value associated with `id` from the `IdCache<I, T, S>`, returning it, or
+    /// returns `None` if `id` has not been assigned, or has already been removed.
+    ///
+    /// `id` is placed on an internal free list, and may be handed back out by a later call to
+    /// `make_id`, `make_id_with`, or `entry`; see the [type-level
+    /// documentation](Self#removal-and-id-recycling) for what this means for any copies of `id`
+    /// a caller may still be holding.
     ///
     /// # Examples
     ///
@@ -278,23 +661,399 @@ impl<I: Id, T> IdCache<I, T> {
     /// # use id_cache::IdCache;
     /// let mut cache: IdCache<u32, &str> = IdCache::new();
     /// let foo_id = cache.make_id("foo");
-    /// assert_eq!(foo_id, 0);
-    /// assert_eq!(cache.get_value(foo_id), Some(&"foo"));
-    /// assert_eq!(cache.get_value(1), None);
+    /// assert_eq!(cache.remove_id(foo_id), Some("foo"));
+    /// assert_eq!(cache.remove_id(foo_id), None);
+    /// assert_eq!(cache.get_value(foo_id), None);
+    ///
+    /// // the freed id is recycled by later insertions:
+    /// assert_eq!(cache.make_id("bar"), foo_id);
     /// ```
-    pub fn get_value(&self, id: I) -> Option<&T> {
-        self.id_to_value.get(id)
+    pub fn remove_id(&mut self, id: I) -> Option<T>
+    where
+        T: Eq + Hash,
+    {
+        let value = self.id_to_value.get_mut(id)?.take()?;
+
+        let hash = hash_value(&self.hash_builder, &value);
+        match self
+            .value_to_id
+            .raw_entry_mut()
+            .from_hash(hash, |&existing_id| existing_id == id)
+        {
+            RawEntryMut::Occupied(entry) => {
+                entry.remove();
+            }
+            RawEntryMut::Vacant(_) => {
+                unreachable!("id_cache: live id missing from value_to_id")
+            }
+        }
+
+        self.free_ids.push(id);
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Removes a value from the `IdCache<I, T, S>`, returning its former id, or returns `None`
+    /// if the value is not present.
+    ///
+    /// See [`remove_id`](Self::remove_id) for details on id recycling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use id_cache::IdCache;
+    /// let mut cache: IdCache<u32, &str> = IdCache::new();
+    /// let foo_id = cache.make_id("foo");
+    /// assert_eq!(cache.remove_value(&"foo"), Some(foo_id));
+    /// assert_eq!(cache.remove_value(&"foo"), None);
+    /// ```
+    pub fn remove_value<U>(&mut self, value: &U) -> Option<I>
+    where
+        T: Borrow<U> + Eq + Hash,
+        U: Eq + Hash,
+    {
+        let id = self.get_id(value)?;
+        self.remove_id(id);
+        Some(id)
+    }
+
+    /// Ensures `value` has an id in the `IdCache<I, T, S>`, and returns an [`Interned`] handle
+    /// wrapping that id, tagged so that [`resolve`](Self::resolve) can check it is later
+    /// resolved against this same cache.
+    ///
+    /// This is otherwise identical to `make_id`, and is useful when a value appears many times
+    /// throughout a larger structure: each `Interned<I>` reference serializes as a bare id, while
+    /// the unique values themselves are serialized once, by the owning `IdCache<I, T, S>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of ids in the `IdCache<I, T, S>` overflows `I`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use id_cache::IdCache;
+    /// let mut cache: IdCache<u32, &str> = IdCache::new();
+    /// let foo = cache.intern("foo");
+    /// assert_eq!(cache.resolve(foo), &"foo");
+    /// ```
+    pub fn intern(&mut self, value: T) -> Interned<I>
+    where
+        T: Eq + Hash,
+    {
+        Interned {
+            id: self.make_id(value),
+            cache_id: self.cache_id,
+        }
+    }
+
+    /// Returns a reference to the value wrapped by an [`Interned`] handle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `handle` was produced by `intern` on a different, still-live `IdCache<I, T, S>`,
+    /// or if the id it wraps has since been removed from this one and not yet reused; once a
+    /// removed id is recycled, the handle resolves to the new value without panicking. This check
+    /// only has a live `cache_id` to compare against when `handle` came straight from `intern`
+    /// (or a clone of such a handle); it cannot fire at all for a handle that was serialized and
+    /// deserialized (deserializing always produces an unchecked tag, which is accepted by every
+    /// cache), so a handle that
+    /// round-trips alongside a cache which has had removals can silently resolve to the wrong
+    /// live value instead of panicking, once ids have been recycled and compacted out from under
+    /// it. Only compare a deserialized `Interned<I>` against a cache whose removal history you
+    /// know matches the one it was interned from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use id_cache::IdCache;
+    /// let mut cache: IdCache<u32, &str> = IdCache::new();
+    /// let foo = cache.intern("foo");
+    /// assert_eq!(cache.resolve(foo), &"foo");
+    /// ```
+    pub fn resolve(&self, handle: Interned<I>) -> &T {
+        assert!(
+            handle.cache_id == UNCHECKED_CACHE_ID || handle.cache_id == self.cache_id,
+            "id_cache: Interned handle does not belong to this IdCache",
+        );
+        &self[handle.id]
+    }
+}
+
+#[cfg(test)]
+mod removal_tests {
+    use crate::IdCache;
+
+    #[test]
+    fn test_remove_id_recycles_freed_id() {
+        let mut cache: IdCache<u32, &str> = IdCache::new();
+        let foo_id = cache.make_id("foo");
+        let bar_id = cache.make_id("bar");
+
+        assert_eq!(cache.remove_id(foo_id), Some("foo"));
+        assert_eq!(cache.remove_id(foo_id), None);
+        assert_eq!(cache.get_value(foo_id), None);
+        assert_eq!(cache.len(), 1);
+
+        // the freed id is recycled rather than a fresh one past `bar_id`
+        assert_eq!(cache.make_id("baz"), foo_id);
+        assert_eq!(cache.get_value(bar_id), Some(&"bar"));
     }
+
+    #[test]
+    fn test_remove_value_then_reinsert_same_value() {
+        let mut cache: IdCache<u32, &str> = IdCache::new();
+        let foo_id = cache.make_id("foo");
+
+        assert_eq!(cache.remove_value(&"foo"), Some(foo_id));
+        assert_eq!(cache.remove_value(&"foo"), None);
+
+        assert_eq!(cache.make_id("foo"), foo_id);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_free_list_reuses_most_recently_freed_id_first() {
+        let mut cache: IdCache<u32, &str> = IdCache::new();
+        let foo_id = cache.make_id("foo");
+        let bar_id = cache.make_id("bar");
+        cache.make_id("baz");
+
+        cache.remove_id(foo_id);
+        cache.remove_id(bar_id);
+
+        assert_eq!(cache.make_id("qux"), bar_id);
+        assert_eq!(cache.make_id("quux"), foo_id);
+    }
+
+    #[test]
+    fn test_entry_draws_from_free_list() {
+        let mut cache: IdCache<u32, &str> = IdCache::new();
+        let foo_id = cache.make_id("foo");
+        cache.remove_id(foo_id);
+
+        assert_eq!(cache.entry("bar").or_id(), foo_id);
+    }
+}
+
+/// A lightweight handle produced by [`IdCache::intern`], which derefs to the wrapped id.
+///
+/// When the `serde` Cargo feature is enabled, `Interned<I>` serializes as nothing more than the
+/// wrapped id: the owning `IdCache<I, T, S>` is what serializes the table of unique values, so a
+/// large structure that references the same value many times (for example, a graph of nodes
+/// sharing interned names) pays for each value once, no matter how many `Interned<I>` handles
+/// point to it.
+///
+/// Serializing a handle this way discards the tag [`IdCache::resolve`] uses to check it belongs
+/// to a particular cache, so a handle deserialized alongside a cache that has had removals is not
+/// protected against the hazard described in the [type-level
+/// documentation](IdCache#removal-and-id-recycling): it can silently resolve to whatever value
+/// was compacted into its old id, rather than panicking. See [`IdCache::resolve`]'s `# Panics`
+/// section for the details of what the check can and cannot catch.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Interned<I> {
+    id: I,
+    cache_id: u64,
 }
 
-impl<I: Id, T, J: Borrow<I>> Index<J> for IdCache<I, T> {
+impl<I> Deref for Interned<I> {
+    type Target = I;
+
+    fn deref(&self) -> &I {
+        &self.id
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<I: serde::Serialize> serde::Serialize for Interned<I> {
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: serde::Serializer,
+    {
+        self.id.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, I: serde::Deserialize<'de>> serde::Deserialize<'de> for Interned<I> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(Interned {
+            id: I::deserialize(deserializer)?,
+            cache_id: UNCHECKED_CACHE_ID,
+        })
+    }
+}
+
+#[cfg(test)]
+mod interned_tests {
+    use crate::IdCache;
+
+    #[test]
+    #[should_panic(expected = "does not belong to this IdCache")]
+    fn test_resolve_panics_across_caches() {
+        let mut cache_a: IdCache<u32, &str> = IdCache::new();
+        let mut cache_b: IdCache<u32, &str> = IdCache::new();
+
+        let foo = cache_a.intern("foo");
+        cache_b.intern("foo");
+
+        cache_b.resolve(foo);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_interned_handle_silently_stale_after_removal_round_trip() {
+        use crate::Interned;
+
+        let mut cache: IdCache<u32, String> = IdCache::new();
+        cache.intern("foo".to_owned());
+        cache.intern("bar".to_owned());
+        let baz = cache.intern("baz".to_owned());
+        cache.intern("qux".to_owned());
+        assert_eq!(cache.resolve(baz), "baz");
+
+        // removing "bar" leaves a hole at its id, which the round trip below compacts away
+        cache.remove_value(&"bar".to_owned());
+
+        let serialized_cache = serde_json::to_string(&cache).unwrap();
+        // `baz`, like any `Interned` handle, serializes as nothing but its bare id when
+        // embedded in a sibling structure
+        let serialized_baz = serde_json::to_string(&baz).unwrap();
+
+        let deserialized_cache =
+            serde_json::from_str::<IdCache<u32, String>>(&serialized_cache).unwrap();
+        let deserialized_baz = serde_json::from_str::<Interned<u32>>(&serialized_baz).unwrap();
+
+        // the deserialized cache renumbered compactly, so `baz`'s id shifted down by one;
+        // resolving the handle deserialized alongside it silently returns the wrong value
+        assert_eq!(deserialized_cache.resolve(deserialized_baz), "qux");
+    }
+}
+
+/// A view into either an occupied or vacant entry of an [`IdCache`], obtained via
+/// [`IdCache::entry`].
+pub enum Entry<'a, I: Id, T, S> {
+    Occupied(OccupiedEntry<'a, I, T>),
+    Vacant(VacantEntry<'a, I, T, S>),
+}
+
+impl<'a, I: Id, T: Eq + Hash, S: BuildHasher> Entry<'a, I, T, S> {
+    /// Returns the id of the entry's value, inserting it into the `IdCache<I, T, S>` first if
+    /// it was not already present.
+    pub fn or_id(self) -> I {
+        match self {
+            Entry::Occupied(entry) => entry.id,
+            Entry::Vacant(entry) => entry.insert(),
+        }
+    }
+
+    /// Returns a reference to the entry's value, whether or not it has been inserted yet.
+    pub fn get(&self) -> &T {
+        match self {
+            Entry::Occupied(entry) => entry.get(),
+            Entry::Vacant(entry) => entry.get(),
+        }
+    }
+
+    /// Calls `f` on the entry's value if it is already present, then returns the entry unchanged
+    /// for further chaining.
+    ///
+    /// Mutating the value in place must not change its hash, as `id_to_value[id]`'s hash must
+    /// stay fixed while cached; if `f` could change it, remove and re-insert the value instead.
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut T),
+    {
+        match self {
+            Entry::Occupied(entry) => {
+                f(entry
+                    .id_to_value
+                    .get_mut(entry.id)
+                    .and_then(Option::as_mut)
+                    .expect("id_cache: occupied entry id missing its value"));
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+/// An occupied [`Entry`].
+pub struct OccupiedEntry<'a, I: Id, T> {
+    id_to_value: &'a mut IdVec<I, Option<T>>,
+    id: I,
+}
+
+impl<'a, I: Id, T> OccupiedEntry<'a, I, T> {
+    /// Returns the entry's id.
+    pub fn id(&self) -> I {
+        self.id
+    }
+
+    /// Returns a reference to the entry's value.
+    pub fn get(&self) -> &T {
+        self.id_to_value[self.id]
+            .as_ref()
+            .expect("id_cache: occupied entry id missing its value")
+    }
+}
+
+/// A vacant [`Entry`].
+pub struct VacantEntry<'a, I: Id, T, S> {
+    id_to_value: &'a mut IdVec<I, Option<T>>,
+    free_ids: &'a mut Vec<I>,
+    len: &'a mut usize,
+    raw_entry: RawVacantEntryMut<'a, I, (), ()>,
+    hash: u64,
+    hash_builder: &'a S,
+    value: T,
+}
+
+impl<'a, I: Id, T: Eq + Hash, S: BuildHasher> VacantEntry<'a, I, T, S> {
+    /// Returns a reference to the value that will be inserted.
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Inserts the entry's value into the `IdCache<I, T, S>`, and returns its new id.
+    pub fn insert(self) -> I {
+        let VacantEntry {
+            id_to_value,
+            free_ids,
+            len,
+            raw_entry,
+            hash,
+            hash_builder,
+            value,
+        } = self;
+
+        let id = insert_live(id_to_value, free_ids, value);
+        *len += 1;
+
+        let id_to_value = &*id_to_value;
+        let (&mut id, _) = raw_entry.insert_with_hasher(hash, id, (), |&id| {
+            hash_value(
+                hash_builder,
+                id_to_value[id]
+                    .as_ref()
+                    .expect("id_cache: live id missing its value"),
+            )
+        });
+        id
+    }
+}
+
+impl<I: Id, T, J: Borrow<I>, S> Index<J> for IdCache<I, T, S> {
     type Output = T;
 
-    /// Returns a reference to the value in the `IdCache<I, T>` associated with a given `id`.
+    /// Returns a reference to the value in the `IdCache<I, T, S>` associated with a given `id`.
     ///
     /// # Panics
     ///
-    /// Panics if `id` has not been assigned.
+    /// Panics if `id` has not been assigned, or has since been removed.
     ///
     /// # Examples
     ///
@@ -307,6 +1066,79 @@ impl<I: Id, T, J: Borrow<I>> Index<J> for IdCache<I, T> {
     #[inline]
     fn index(&self, id: J) -> &Self::Output {
         let id = *id.borrow();
-        &self.id_to_value[id]
+        self.id_to_value[id]
+            .as_ref()
+            .expect("id_cache: id has not been assigned, or has been removed")
+    }
+}
+
+/// An iterator over the `(id, value)` pairs of an [`IdCache`], obtained by its `IntoIterator`
+/// impl. See [`IdCache::iter`] for the borrowing equivalent.
+pub struct IntoIter<I: Id, T> {
+    inner: <IdVec<I, Option<T>> as IntoIterator>::IntoIter,
+}
+
+impl<I: Id, T> Iterator for IntoIter<I, T> {
+    type Item = (I, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (id, value) in self.inner.by_ref() {
+            if let Some(value) = value {
+                return Some((id, value));
+            }
+        }
+        None
+    }
+}
+
+impl<I: Id, T, S> IntoIterator for IdCache<I, T, S> {
+    type Item = (I, T);
+    type IntoIter = IntoIter<I, T>;
+
+    /// Returns an iterator over the `(id, value)` pairs of the unique values in the
+    /// `IdCache<I, T, S>`, ordered by id.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use id_cache::IdCache;
+    /// let mut cache: IdCache<u32, &str> = IdCache::new();
+    /// cache.make_id("foo");
+    /// cache.make_id("bar");
+    /// assert_eq!(cache.into_iter().collect::<Vec<_>>(), vec![(0, "foo"), (1, "bar")]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            inner: self.id_to_value.into_iter(),
+        }
+    }
+}
+
+impl<I: Id, T: Eq + Hash, S: BuildHasher> Extend<T> for IdCache<I, T, S> {
+    /// Inserts each value from `iter` into the `IdCache<I, T, S>` via `make_id`, discarding the
+    /// assigned ids. Duplicate values (whether among `iter` or already present) collapse to a
+    /// single entry.
+    fn extend<It: IntoIterator<Item = T>>(&mut self, iter: It) {
+        for value in iter {
+            self.make_id(value);
+        }
+    }
+}
+
+impl<I: Id, T: Eq + Hash> FromIterator<T> for IdCache<I, T> {
+    /// Builds an `IdCache<I, T>` from an iterator of values, collapsing duplicates via
+    /// `make_id`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use id_cache::IdCache;
+    /// let cache: IdCache<u32, &str> = ["foo", "bar", "foo"].into_iter().collect();
+    /// assert_eq!(cache.len(), 2);
+    /// ```
+    fn from_iter<It: IntoIterator<Item = T>>(iter: It) -> Self {
+        let mut cache = IdCache::new();
+        cache.extend(iter);
+        cache
     }
 }